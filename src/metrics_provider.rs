@@ -53,6 +53,32 @@ lazy_static! {
 
         addr
     };
+    static ref COLLECT_INTERVAL: Duration = {
+        let def = Duration::from_secs(10);
+
+        let Some(secs) = env::var_os("COLLECT_INTERVAL_SECS") else {
+            info!("use default collect interval: {}s", def.as_secs());
+            return def;
+        };
+
+        let Some(secs) = secs.to_str().and_then(|s| s.parse::<u64>().ok()) else {
+            error!(
+                "cannot parse collect interval, use default collect interval: {}s",
+                def.as_secs()
+            );
+            return def;
+        };
+
+        if secs == 0 {
+            error!(
+                "collect interval must not be zero, use default collect interval: {}s",
+                def.as_secs()
+            );
+            return def;
+        }
+
+        Duration::from_secs(secs)
+    };
 }
 
 #[derive(Default)]
@@ -113,8 +139,8 @@ impl MetricsProvider {
     }
 
     async fn data_collector(latitude: f64, longitude: f64) {
-        // configure interval of wind speed and wind direction samples in seconds
-        let mut collect_interval = tokio::time::interval(Duration::from_secs(10));
+        // configure interval of wind speed and wind direction samples
+        let mut collect_interval = tokio::time::interval(*COLLECT_INTERVAL);
 
         // init simulation ranges
         let wind_speed_range: Uniform<f64> = Uniform::new_inclusive(0.0, 10.0);
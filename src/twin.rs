@@ -1,10 +1,14 @@
 use crate::metrics_provider::MetricsProvider;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use azure_iot_sdk::client::*;
 use log::{error, info};
 use rand::{thread_rng, Rng};
 use serde_json::json;
-use tokio::{select, sync::mpsc};
+use tokio::{
+    select,
+    signal::unix::{signal, SignalKind},
+    sync::mpsc,
+};
 
 pub struct Twin {
     iothub_client: IotHubClient,
@@ -42,7 +46,8 @@ impl Twin {
                             "module-version": env!("CARGO_PKG_VERSION"),
                             "azure-sdk-version": IotHubClient::sdk_version_string()
                         }))
-                        .await?;
+                        .await
+                        .context("failed to queue version report")?;
                 };
             }
             AuthenticationStatus::Unauthenticated(reason) => {
@@ -63,19 +68,40 @@ impl Twin {
     ) -> Result<()> {
         info!("desired: {state:#?}, {desired}");
 
-        let coordinates = desired["reported"]["location"].as_object();
+        let coordinates = &desired["reported"]["location"];
         if !self.location_once {
-            let location = match coordinates {
-                Some(values) => json!({ "location": values }),
-                _ => json!({
-                    "location": {
-                        "latitude": thread_rng().gen_range(53.908754f64..53.956915f64),
-                        "longitude": thread_rng().gen_range(8.594901f64..8.741848f64)
+            let (latitude, longitude) = match (
+                coordinates["latitude"].as_f64(),
+                coordinates["longitude"].as_f64(),
+            ) {
+                (Some(latitude), Some(longitude))
+                    if (-90.0..=90.0).contains(&latitude)
+                        && (-180.0..=180.0).contains(&longitude) =>
+                {
+                    (latitude, longitude)
+                }
+                _ => {
+                    if !coordinates.is_null() {
+                        error!("invalid location: {coordinates}, use random location");
                     }
-                }),
+                    (
+                        thread_rng().gen_range(53.908754f64..53.956915f64),
+                        thread_rng().gen_range(8.594901f64..8.741848f64),
+                    )
+                }
             };
 
-            self.tx_reported_properties.send(location.clone()).await?;
+            let location = json!({
+                "location": {
+                    "latitude": latitude,
+                    "longitude": longitude
+                }
+            });
+
+            self.tx_reported_properties
+                .send(location.clone())
+                .await
+                .context("failed to queue location report")?;
 
             self.metrics_provider.run(location["location"].clone());
 
@@ -92,22 +118,46 @@ impl Twin {
             IotHubClient::builder()
                 .observe_connection_state(tx_connection_status)
                 .observe_desired_properties(tx_twin_desired)
-                .build_edge_client()?,
+                .build_edge_client()
+                .context("failed to build edge client")?,
         );
 
+        let mut sigterm =
+            signal(SignalKind::terminate()).context("failed to register SIGTERM handler")?;
+        let mut sigint =
+            signal(SignalKind::interrupt()).context("failed to register SIGINT handler")?;
+
         loop {
             select! (
+                _ = sigterm.recv() => {
+                    info!("received SIGTERM, shutting down");
+                    return Ok(());
+                },
+                _ = sigint.recv() => {
+                    info!("received SIGINT, shutting down");
+                    return Ok(());
+                },
                 status = rx_connection_status.recv() => {
-                    twin.handle_connection_status(status.unwrap()).await?;
+                    let Some(status) = status else {
+                        anyhow::bail!("connection status channel closed");
+                    };
+                    twin.handle_connection_status(status).await?;
                 },
                 desired = rx_twin_desired.recv() => {
-                    let desired = desired.unwrap();
+                    let Some(desired) = desired else {
+                        anyhow::bail!("desired properties channel closed");
+                    };
                     twin.handle_desired(desired.state, desired.value)
                         .await
                         .unwrap_or_else(|e| error!("twin update desired properties: {e:#}"));
                 },
                 reported = twin.rx_reported_properties.recv() => {
-                    twin.iothub_client.twin_report(reported.unwrap())?
+                    let Some(reported) = reported else {
+                        anyhow::bail!("reported properties channel closed");
+                    };
+                    twin.iothub_client
+                        .twin_report(reported)
+                        .unwrap_or_else(|e| error!("twin report: {e:#}"));
                 },
             );
         }